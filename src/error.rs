@@ -1,65 +1,463 @@
 use crate::{
-    RawOsError,
     kind::{ErrorKind, FromRawOsError},
+    RawOsError,
 };
 
 #[cfg(feature = "alloc")]
 use alloc::boxed::Box;
 
-#[derive(Debug)]
-enum ErrorPayload {
-    Simple,
-    RawOsError(RawOsError),
-    Message(&'static str),
-    #[cfg(feature = "alloc")]
-    Error(Box<dyn core::error::Error + Send + Sync + 'static>),
+#[cfg(feature = "error-track_caller")]
+cfg_match::cfg_match! {
+    feature = "alloc" => {
+        mod trail {
+            //! Growable propagation trail, kept when an allocator is available.
+            use alloc::vec::Vec;
+            use core::panic::Location;
+
+            #[derive(Debug)]
+            pub(super) struct Trail(Vec<(&'static Location<'static>, Option<&'static str>)>);
+
+            impl Trail {
+                pub fn new(location: &'static Location<'static>) -> Self {
+                    Trail([(location, None)].into())
+                }
+
+                pub fn push(&mut self, location: &'static Location<'static>, msg: Option<&'static str>) {
+                    self.0.push((location, msg));
+                }
+
+                pub fn iter(
+                    &self,
+                ) -> impl DoubleEndedIterator<Item = (&'static Location<'static>, Option<&'static str>)> + '_
+                {
+                    self.0.iter().copied()
+                }
+            }
+        }
+    }
+    _ => {
+        mod trail {
+            //! Zero-history fast path: a single inline location, used when no allocator is available.
+            use core::panic::Location;
+
+            #[derive(Debug)]
+            pub(super) struct Trail {
+                location: &'static Location<'static>,
+                message: Option<&'static str>,
+            }
+
+            impl Trail {
+                pub const fn new(location: &'static Location<'static>) -> Self {
+                    Trail { location, message: None }
+                }
+
+                pub fn push(&mut self, location: &'static Location<'static>, msg: Option<&'static str>) {
+                    self.location = location;
+                    self.message = msg;
+                }
+
+                pub fn iter(
+                    &self,
+                ) -> impl DoubleEndedIterator<Item = (&'static Location<'static>, Option<&'static str>)> + '_
+                {
+                    core::iter::once((self.location, self.message))
+                }
+            }
+        }
+    }
 }
 
+cfg_match::cfg_match! {
+    all(feature = "bitpacked", target_pointer_width = "64") => {
+        mod repr {
+            //! Pointer-sized, bit-packed payload representation.
+            //!
+            //! This mirrors the tagged-pointer trick `std::io::Error` uses internally: the low
+            //! two bits of a single `usize` are a tag, and the remaining bits are either a
+            //! pointer-aligned heap pointer or (for `Os`) a sign-extended error code shifted up
+            //! past the tag. `Message` and `Custom` box their payload to keep the word thin;
+            //! `Os` stays inline so `Error::from_raw_os_error` never allocates.
+            use crate::RawOsError;
+            use alloc::boxed::Box;
+
+            const TAG_BITS: u32 = 2;
+            const TAG_MASK: usize = 0b11;
+            const TAG_SIMPLE: usize = 0b00;
+            const TAG_OS: usize = 0b01;
+            const TAG_MESSAGE: usize = 0b10;
+            const TAG_CUSTOM: usize = 0b11;
+
+            type CustomError = dyn core::error::Error + Send + Sync + 'static;
+
+            /// Heap slot holding a `&'static str`, so a `Message` payload is a thin, one-word pointer.
+            struct MessageSlot(&'static str);
+
+            pub(super) struct Repr(usize);
+
+            impl Repr {
+                pub const fn simple() -> Self {
+                    Repr(TAG_SIMPLE)
+                }
+
+                /// Packs `raw` into the word inline, so this never touches the allocator.
+                ///
+                /// The human-readable description (when one is available) is *not* stored here:
+                /// `Repr` doesn't carry the kind type, so it can't call
+                /// [`ErrorKind::describe_raw_os_error`](crate::kind::ErrorKind::describe_raw_os_error)
+                /// itself. Instead [`fmt_payload`](Self::fmt_payload) takes the describer as a
+                /// parameter and the caller (`Error<K>`'s `Display` impl, which does know `K`)
+                /// passes `K::describe_raw_os_error` in, resolving it lazily at `Display` time
+                /// while keeping `from_raw_os_error` allocation-free.
+                ///
+                /// `raw` is sign-extended and shifted left by [`TAG_BITS`] to make room for the
+                /// tag, so only the low `usize::BITS - TAG_BITS` bits of its magnitude survive.
+                /// That's always true of the common 32-bit `RawOsError`, but on `windows`/`lilium`
+                /// targets (where [`crate::RawOsError`] is `isize`) a code whose magnitude needs
+                /// more than about 61 bits would silently lose its top bits; a `debug_assert!`
+                /// below catches that in debug builds rather than decoding to a different code.
+                pub const fn os(raw: RawOsError) -> Self {
+                    let packed = ((raw as isize as usize) << TAG_BITS) | TAG_OS;
+                    debug_assert!(
+                        ((packed as isize) >> TAG_BITS) as RawOsError == raw,
+                        "RawOsError magnitude does not fit in the bit-packed Os payload \
+                         (the top tag bits would be lost); this representation can only hold \
+                         codes within roughly +/- 2^61"
+                    );
+                    Repr(packed)
+                }
+
+                pub fn message(msg: &'static str) -> Self {
+                    let ptr = Box::into_raw(Box::new(MessageSlot(msg)));
+                    Repr(ptr as usize | TAG_MESSAGE)
+                }
+
+                #[cfg(feature = "alloc")]
+                pub fn custom(err: Box<CustomError>) -> Self {
+                    // Double-boxed so the fat `Box<dyn Error>` pointer lives on the heap and
+                    // `Repr` only needs to store a thin pointer to it.
+                    let ptr = Box::into_raw(Box::new(err));
+                    Repr(ptr as usize | TAG_CUSTOM)
+                }
+
+                fn tag(&self) -> usize {
+                    self.0 & TAG_MASK
+                }
+
+                fn data_ptr<T>(&self) -> *mut T {
+                    (self.0 & !TAG_MASK) as *mut T
+                }
+
+                pub fn raw_os_error(&self) -> Option<RawOsError> {
+                    (self.tag() == TAG_OS)
+                        .then_some(((self.0 as isize) >> TAG_BITS) as RawOsError)
+                }
+
+                pub fn as_custom(&self) -> Option<&CustomError> {
+                    (self.tag() == TAG_CUSTOM).then(|| unsafe { &**self.data_ptr::<Box<CustomError>>() })
+                }
+
+                #[cfg(feature = "alloc")]
+                pub fn as_custom_mut(&mut self) -> Option<&mut CustomError> {
+                    (self.tag() == TAG_CUSTOM)
+                        .then(|| unsafe { &mut **self.data_ptr::<Box<CustomError>>() })
+                }
+
+                #[cfg(feature = "alloc")]
+                pub fn into_error(self) -> Option<Box<CustomError>> {
+                    match self.tag() {
+                        TAG_MESSAGE => {
+                            let slot = unsafe { Box::from_raw(self.data_ptr::<MessageSlot>()) };
+                            core::mem::forget(self);
+                            Some(slot.0.into())
+                        }
+                        TAG_CUSTOM => self.into_custom(),
+                        _ => None,
+                    }
+                }
+
+                #[cfg(feature = "alloc")]
+                pub fn into_custom(self) -> Option<Box<CustomError>> {
+                    if self.tag() == TAG_CUSTOM {
+                        let boxed = unsafe { Box::from_raw(self.data_ptr::<Box<CustomError>>()) };
+                        core::mem::forget(self);
+                        Some(*boxed)
+                    } else {
+                        None
+                    }
+                }
+
+                /// Formats the payload, given a `describe` hook to resolve an `Os` payload's
+                /// human-readable description (typically
+                /// [`K::describe_raw_os_error`](crate::kind::ErrorKind::describe_raw_os_error),
+                /// supplied by the caller since `Repr` itself doesn't carry `K`).
+                pub fn fmt_payload(
+                    &self,
+                    f: &mut core::fmt::Formatter<'_>,
+                    describe: impl FnOnce(RawOsError) -> Option<&'static str>,
+                ) -> core::fmt::Result {
+                    match self.tag() {
+                        TAG_SIMPLE => Ok(()),
+                        TAG_OS => {
+                            let raw = self.raw_os_error().unwrap();
+                            match describe(raw) {
+                                Some(description) => {
+                                    f.write_fmt(format_args!(": {description}"))
+                                }
+                                None => f.write_fmt(format_args!(" (raw os error {raw})")),
+                            }
+                        }
+                        TAG_MESSAGE => {
+                            let msg = unsafe { (*self.data_ptr::<MessageSlot>()).0 };
+                            f.write_fmt(format_args!(": {msg}"))
+                        }
+                        _ => f.write_fmt(format_args!(": {}", self.as_custom().unwrap())),
+                    }
+                }
+            }
+
+            impl Drop for Repr {
+                fn drop(&mut self) {
+                    match self.tag() {
+                        TAG_MESSAGE => drop(unsafe { Box::from_raw(self.data_ptr::<MessageSlot>()) }),
+                        TAG_CUSTOM => {
+                            drop(unsafe { Box::from_raw(self.data_ptr::<Box<CustomError>>()) })
+                        }
+                        _ => {}
+                    }
+                }
+            }
+
+            impl core::fmt::Debug for Repr {
+                fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                    match self.tag() {
+                        TAG_SIMPLE => f.write_str("Simple"),
+                        TAG_OS => f.debug_tuple("Os").field(&self.raw_os_error().unwrap()).finish(),
+                        TAG_MESSAGE => f
+                            .debug_tuple("Message")
+                            .field(&unsafe { (*self.data_ptr::<MessageSlot>()).0 })
+                            .finish(),
+                        _ => f.debug_tuple("Custom").field(&self.as_custom().unwrap()).finish(),
+                    }
+                }
+            }
+        }
+    }
+    _ => {
+        mod repr {
+            //! Plain enum payload representation, used on non-64-bit targets or when the
+            //! `bitpacked` feature is disabled.
+            use crate::RawOsError;
+            #[cfg(feature = "alloc")]
+            use alloc::boxed::Box;
+
+            #[derive(Debug)]
+            pub(super) enum Repr {
+                Simple,
+                Os(RawOsError, Option<&'static str>),
+                Message(&'static str),
+                #[cfg(feature = "alloc")]
+                Custom(Box<dyn core::error::Error + Send + Sync + 'static>),
+            }
+
+            impl Repr {
+                pub const fn simple() -> Self {
+                    Repr::Simple
+                }
+
+                pub const fn os(raw: RawOsError, description: Option<&'static str>) -> Self {
+                    Repr::Os(raw, description)
+                }
+
+                pub const fn message(msg: &'static str) -> Self {
+                    Repr::Message(msg)
+                }
+
+                #[cfg(feature = "alloc")]
+                pub fn custom(err: Box<dyn core::error::Error + Send + Sync + 'static>) -> Self {
+                    Repr::Custom(err)
+                }
+
+                pub fn raw_os_error(&self) -> Option<RawOsError> {
+                    match self {
+                        Repr::Os(e, _) => Some(*e),
+                        _ => None,
+                    }
+                }
+
+                #[cfg(feature = "alloc")]
+                pub fn as_custom(&self) -> Option<&(dyn core::error::Error + Send + Sync + 'static)> {
+                    match self {
+                        Repr::Custom(e) => Some(&**e),
+                        _ => None,
+                    }
+                }
+
+                #[cfg(feature = "alloc")]
+                pub fn as_custom_mut(
+                    &mut self,
+                ) -> Option<&mut (dyn core::error::Error + Send + Sync + 'static)> {
+                    match self {
+                        Repr::Custom(e) => Some(&mut **e),
+                        _ => None,
+                    }
+                }
+
+                #[cfg(feature = "alloc")]
+                pub fn into_error(self) -> Option<Box<dyn core::error::Error + Send + Sync + 'static>> {
+                    match self {
+                        Repr::Custom(e) => Some(e),
+                        Repr::Message(m) => Some(m.into()),
+                        _ => None,
+                    }
+                }
+
+                #[cfg(feature = "alloc")]
+                pub fn into_custom(self) -> Option<Box<dyn core::error::Error + Send + Sync + 'static>> {
+                    match self {
+                        Repr::Custom(e) => Some(e),
+                        _ => None,
+                    }
+                }
+
+                pub fn fmt_payload(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                    match self {
+                        Repr::Simple => Ok(()),
+                        Repr::Os(_os, Some(description)) => {
+                            f.write_fmt(format_args!(": {description}"))
+                        }
+                        Repr::Os(os, None) => f.write_fmt(format_args!(" (raw os error {os})")),
+                        Repr::Message(m) => f.write_fmt(format_args!(": {m}")),
+                        #[cfg(feature = "alloc")]
+                        Repr::Custom(error) => f.write_fmt(format_args!(": {error}")),
+                    }
+                }
+            }
+        }
+    }
+}
+
+use repr::Repr;
+
 /// Primary Error type from this crate.
 /// [`Error`] contains a kind and an optional payload, which may be an OS Error Code, a Custom Message, or (with an allocator available) a custom Error object.
 #[derive(Debug)]
 pub struct Error<K> {
     kind: K,
-    payload: ErrorPayload,
+    repr: Repr,
+    /// The propagation trail: where the error was created, plus every [`Error::track`]/[`Error::track_msg`] hop since.
     #[cfg(feature = "error-track_caller")]
-    #[allow(dead_code)]
-    caller_location: &'static core::panic::Location<'static>, // intentionally unused field
+    trail: trail::Trail,
 }
 
 impl<K: ErrorKind> core::fmt::Display for Error<K> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         core::fmt::Display::fmt(&self.kind, f)?;
-        match &self.payload {
-            ErrorPayload::Simple => Ok(()),
-            ErrorPayload::RawOsError(os) => f.write_fmt(format_args!(" (raw os error {os})")),
-            ErrorPayload::Message(m) => f.write_fmt(format_args!(": {m}")),
-            #[cfg(feature = "alloc")]
-            ErrorPayload::Error(error) => f.write_fmt(format_args!(": {error}")),
+        #[cfg(all(feature = "bitpacked", target_pointer_width = "64"))]
+        self.repr.fmt_payload(f, K::describe_raw_os_error)?;
+        #[cfg(not(all(feature = "bitpacked", target_pointer_width = "64")))]
+        self.repr.fmt_payload(f)?;
+        #[cfg(feature = "error-track_caller")]
+        for (location, msg) in self.trail.iter().rev() {
+            match msg {
+                Some(msg) => f.write_fmt(format_args!("\n    at {location}: {msg}"))?,
+                None => f.write_fmt(format_args!("\n    at {location}"))?,
+            }
         }
+        Ok(())
     }
 }
 
 impl<K> Error<K> {
+    #[cfg(not(all(feature = "error-track_caller", feature = "alloc")))]
     #[cfg_attr(feature = "error-track_caller", track_caller)]
-    const fn internal_new(kind: K, payload: ErrorPayload) -> Self {
+    const fn internal_new(kind: K, repr: Repr) -> Self {
         Self {
             kind,
-            payload,
+            repr,
             #[cfg(feature = "error-track_caller")]
-            caller_location: core::panic::Location::caller(),
+            trail: trail::Trail::new(core::panic::Location::caller()),
+        }
+    }
+
+    /// Records the trail entry at construction time; not `const` because the `alloc` trail is a growable `Vec`.
+    #[cfg(all(feature = "error-track_caller", feature = "alloc"))]
+    #[track_caller]
+    fn internal_new(kind: K, repr: Repr) -> Self {
+        Self {
+            kind,
+            repr,
+            trail: trail::Trail::new(core::panic::Location::caller()),
         }
     }
 
     /// Constructs a new error with a kind, but not payload
+    #[cfg(not(all(feature = "error-track_caller", feature = "alloc")))]
     #[cfg_attr(feature = "error-track_caller", track_caller)]
     pub const fn new_simple(kind: K) -> Self {
-        Self::internal_new(kind, ErrorPayload::Simple)
+        Self::internal_new(kind, Repr::simple())
+    }
+
+    /// Constructs a new error with a kind, but not payload
+    ///
+    /// Note: with `error-track_caller` and `alloc` both enabled, the trail is recorded in a growable buffer, so this is not `const`.
+    #[cfg(all(feature = "error-track_caller", feature = "alloc"))]
+    #[track_caller]
+    pub fn new_simple(kind: K) -> Self {
+        Self::internal_new(kind, Repr::simple())
+    }
+
+    /// Records the caller's location in the propagation trail and returns `self`.
+    /// Intended for annotating each `?`-style bubble-up point; see also the [`track!`](macro@crate::track) macro.
+    #[cfg(feature = "error-track_caller")]
+    #[track_caller]
+    pub fn track(mut self) -> Self {
+        self.trail.push(core::panic::Location::caller(), None);
+        self
+    }
+
+    /// Like [`Error::track`], but also records a static message alongside the location.
+    #[cfg(feature = "error-track_caller")]
+    #[track_caller]
+    pub fn track_msg(mut self, msg: &'static str) -> Self {
+        self.trail.push(core::panic::Location::caller(), Some(msg));
+        self
+    }
+
+    /// Returns the propagation trail: the location (and optional message) of where the error was
+    /// created, followed by every [`Error::track`]/[`Error::track_msg`] hop, oldest first.
+    #[cfg(feature = "error-track_caller")]
+    pub fn locations(
+        &self,
+    ) -> impl DoubleEndedIterator<
+        Item = (
+            &'static core::panic::Location<'static>,
+            Option<&'static str>,
+        ),
+    > + '_ {
+        self.trail.iter()
     }
 
     /// Constructs a new error with a kind and a custom message
+    #[cfg(not(any(
+        all(feature = "bitpacked", target_pointer_width = "64"),
+        all(feature = "error-track_caller", feature = "alloc")
+    )))]
     #[cfg_attr(feature = "error-track_caller", track_caller)]
     pub const fn new_with_message(kind: K, msg: &'static str) -> Self {
-        Self::internal_new(kind, ErrorPayload::Message(msg))
+        Self::internal_new(kind, Repr::message(msg))
+    }
+
+    /// Constructs a new error with a kind and a custom message
+    ///
+    /// Note: this is not `const` when the `bitpacked` feature boxes the message, or when
+    /// `error-track_caller` and `alloc` are both enabled and the trail is a growable buffer.
+    #[cfg(any(
+        all(feature = "bitpacked", target_pointer_width = "64"),
+        all(feature = "error-track_caller", feature = "alloc")
+    ))]
+    #[cfg_attr(feature = "error-track_caller", track_caller)]
+    pub fn new_with_message(kind: K, msg: &'static str) -> Self {
+        Self::internal_new(kind, Repr::message(msg))
     }
 
     /// Constructs a new error with a kind and a custom payload
@@ -69,7 +467,7 @@ impl<K> Error<K> {
         kind: K,
         error: E,
     ) -> Self {
-        Self::internal_new(kind, ErrorPayload::Error(error.into()))
+        Self::internal_new(kind, Repr::custom(error.into()))
     }
 
     /// Returns the error Kind of the Error
@@ -82,10 +480,7 @@ impl<K> Error<K> {
 
     /// Returns the raw os error if the error was constructed with one (via. [`Error::from_raw_os_error`])
     pub fn raw_os_error(&self) -> Option<RawOsError> {
-        match self.payload {
-            ErrorPayload::RawOsError(e) => Some(e),
-            _ => None,
-        }
+        self.repr.raw_os_error()
     }
 
     /// Converts `self` into an boxed error if a custom payload is present.
@@ -95,54 +490,114 @@ impl<K> Error<K> {
     /// Note that the latter case cannot be downcast.
     #[cfg(feature = "alloc")]
     pub fn into_error(self) -> Option<Box<dyn core::error::Error + Send + Sync + 'static>> {
-        match self.payload {
-            ErrorPayload::Error(payload) => Some(payload),
-            ErrorPayload::Message(m) => Some(m.into()),
-            _ => None,
-        }
+        self.repr.into_error()
     }
 
     /// Converts `self` into an boxed error if a custom payload is present.
     /// [`Error::into_inner`] will return [`Some`] if constructed with a custom payload ([`Error::new`], [`Error::other`], or [`Error::uncategorized`]), and [`None`] otherwise.
     #[cfg(feature = "alloc")]
     pub fn into_inner(self) -> Option<Box<dyn core::error::Error + Send + Sync + 'static>> {
-        match self.payload {
-            ErrorPayload::Error(payload) => Some(payload),
-            _ => None,
+        self.repr.into_custom()
+    }
+
+    /// Returns a reference to the inner error if a custom payload is present ([`Error::new`], [`Error::other`], or [`Error::uncategorized`]), and [`None`] otherwise.
+    #[cfg(feature = "alloc")]
+    pub fn get_ref(&self) -> Option<&(dyn core::error::Error + Send + Sync + 'static)> {
+        self.repr.as_custom()
+    }
+
+    /// Returns a mutable reference to the inner error if a custom payload is present ([`Error::new`], [`Error::other`], or [`Error::uncategorized`]), and [`None`] otherwise.
+    #[cfg(feature = "alloc")]
+    pub fn get_mut(&mut self) -> Option<&mut (dyn core::error::Error + Send + Sync + 'static)> {
+        self.repr.as_custom_mut()
+    }
+
+    /// Attempts to downcast the inner error (if any) to a concrete type `E`.
+    ///
+    /// On success, returns the downcast value. On failure (no custom payload, or the payload
+    /// isn't an `E`), returns `self` unchanged so it can still be inspected or propagated.
+    #[cfg(feature = "alloc")]
+    pub fn downcast<E: core::error::Error + 'static>(self) -> Result<E, Self> {
+        if self.repr.as_custom().is_none() {
+            return Err(self);
+        }
+        let Error {
+            kind,
+            repr,
+            #[cfg(feature = "error-track_caller")]
+            trail,
+        } = self;
+        // `repr` was just confirmed to hold a custom payload, so this can't be `None`.
+        let boxed = repr.into_custom().unwrap();
+        match boxed.downcast::<E>() {
+            Ok(value) => Ok(*value),
+            Err(boxed) => Err(Error {
+                kind,
+                repr: Repr::custom(boxed),
+                #[cfg(feature = "error-track_caller")]
+                trail,
+            }),
         }
     }
 }
 
 impl<K: ErrorKind> Error<K> {
     /// Constructs a new [`Error`] with no payload that indicates an other error.
+    #[cfg(not(all(feature = "error-track_caller", feature = "alloc")))]
     #[cfg_attr(feature = "error-track_caller", track_caller)]
     pub const fn other_simple() -> Self {
-        Self::internal_new(K::OTHER, ErrorPayload::Simple)
+        Self::internal_new(K::OTHER, Repr::simple())
+    }
+
+    /// Constructs a new [`Error`] with no payload that indicates an other error.
+    ///
+    /// Note: with `error-track_caller` and `alloc` both enabled, the trail is recorded in a growable buffer, so this is not `const`.
+    #[cfg(all(feature = "error-track_caller", feature = "alloc"))]
+    #[track_caller]
+    pub fn other_simple() -> Self {
+        Self::internal_new(K::OTHER, Repr::simple())
     }
 
     /// Constructs a new [`Error`] with a custom message that indicates an other error.
+    #[cfg(not(any(
+        all(feature = "bitpacked", target_pointer_width = "64"),
+        all(feature = "error-track_caller", feature = "alloc")
+    )))]
     #[cfg_attr(feature = "error-track_caller", track_caller)]
     pub const fn other_with_message(msg: &'static str) -> Self {
-        Self::internal_new(K::OTHER, ErrorPayload::Message(msg))
+        Self::internal_new(K::OTHER, Repr::message(msg))
+    }
+
+    /// Constructs a new [`Error`] with a custom message that indicates an other error.
+    ///
+    /// Note: this is not `const` when the `bitpacked` feature boxes the message, or when
+    /// `error-track_caller` and `alloc` are both enabled and the trail is a growable buffer.
+    #[cfg(any(
+        all(feature = "bitpacked", target_pointer_width = "64"),
+        all(feature = "error-track_caller", feature = "alloc")
+    ))]
+    #[cfg_attr(feature = "error-track_caller", track_caller)]
+    pub fn other_with_message(msg: &'static str) -> Self {
+        Self::internal_new(K::OTHER, Repr::message(msg))
     }
 
     /// Constructs a new [`Error`] with a custom payload that indicates an other error.
     #[cfg_attr(feature = "error-track_caller", track_caller)]
     #[cfg(feature = "alloc")]
     pub fn other<E: Into<Box<dyn core::error::Error + Send + Sync + 'static>>>(error: E) -> Self {
-        Self::internal_new(K::OTHER, ErrorPayload::Error(error.into()))
+        Self::internal_new(K::OTHER, Repr::custom(error.into()))
     }
 
     /// Constructs a new [`Error`] with no payload that indicates an uncategorized error.
     #[cfg_attr(feature = "error-track_caller", track_caller)]
     pub fn uncategorized_simple() -> Self {
-        Self::internal_new(K::uncategorized(), ErrorPayload::Simple)
+        Self::internal_new(K::uncategorized(), Repr::simple())
     }
 
     /// Constructs a new [`Error`] with a custom message that indicates an uncategorized error.
     #[cfg_attr(feature = "error-track_caller", track_caller)]
     pub fn uncategorized_with_message(msg: &'static str) -> Self {
-        Self::internal_new(K::uncategorized(), ErrorPayload::Message(msg))
+        Self::internal_new(K::uncategorized(), Repr::message(msg))
     }
 
     /// Constructs a new [`Error`] with a custom payload that indicates an uncategorized error.
@@ -151,19 +606,43 @@ impl<K: ErrorKind> Error<K> {
     pub fn uncategorized<E: Into<Box<dyn core::error::Error + Send + Sync + 'static>>>(
         error: E,
     ) -> Self {
-        Self::internal_new(K::uncategorized(), ErrorPayload::Error(error.into()))
+        Self::internal_new(K::uncategorized(), Repr::custom(error.into()))
     }
 }
 
 impl<K: FromRawOsError> Error<K> {
     /// Constructs a new [`Error`] that contains an Error created from the OS
+    #[cfg(not(all(feature = "bitpacked", target_pointer_width = "64")))]
     #[cfg_attr(feature = "error-track_caller", track_caller)]
     pub fn from_raw_os_error(error: RawOsError) -> Self {
-        Self::internal_new(K::from_raw_os_error(error), ErrorPayload::RawOsError(error))
+        Self::internal_new(
+            K::from_raw_os_error(error),
+            Repr::os(error, K::describe_raw_os_error(error)),
+        )
+    }
+
+    /// Constructs a new [`Error`] that contains an Error created from the OS
+    ///
+    /// Note: with `bitpacked` enabled, the `Os` payload stays a single allocation-free inline
+    /// word; its description (if any) is resolved lazily when the error is displayed rather than
+    /// eagerly here, so [`K::describe_raw_os_error`](crate::kind::ErrorKind::describe_raw_os_error)
+    /// isn't called by this constructor - `Display` calls it directly instead, so any override is
+    /// still honored.
+    #[cfg(all(feature = "bitpacked", target_pointer_width = "64"))]
+    #[cfg_attr(feature = "error-track_caller", track_caller)]
+    pub fn from_raw_os_error(error: RawOsError) -> Self {
+        Self::internal_new(K::from_raw_os_error(error), Repr::os(error))
     }
 }
 
-impl<K: ErrorKind> core::error::Error for Error<K> {}
+impl<K: ErrorKind> core::error::Error for Error<K> {
+    #[cfg(feature = "alloc")]
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        self.repr
+            .as_custom()
+            .map(|error| error as &(dyn core::error::Error + 'static))
+    }
+}
 
 #[cfg(feature = "std")]
 impl<K: crate::kind::FromIoKind> From<std::io::ErrorKind> for Error<K> {
@@ -179,11 +658,16 @@ impl<K: crate::kind::FromIoKind> From<std::io::Error> for Error<K> {
     fn from(value: std::io::Error) -> Self {
         let kind = K::from_io_error_kind(value.kind());
         if let Some(code) = value.raw_os_error() {
-            Self::internal_new(kind, ErrorPayload::RawOsError(code))
+            #[cfg(not(all(feature = "bitpacked", target_pointer_width = "64")))]
+            let repr = Repr::os(code, K::describe_raw_os_error(code));
+            // The bitpacked `Os` payload resolves its description lazily at `Display` time.
+            #[cfg(all(feature = "bitpacked", target_pointer_width = "64"))]
+            let repr = Repr::os(code);
+            Self::internal_new(kind, repr)
         } else if let Some(data) = value.into_inner() {
-            Self::internal_new(kind, ErrorPayload::Error(data))
+            Self::internal_new(kind, Repr::custom(data))
         } else {
-            Self::internal_new(kind, ErrorPayload::Simple)
+            Self::internal_new(kind, Repr::simple())
         }
     }
 }
@@ -204,3 +688,233 @@ impl<K: crate::kind::IntoIoKind> From<Error<K>> for std::io::Error {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    enum TestKind {
+        Other,
+        Uncategorized,
+    }
+
+    impl core::fmt::Display for TestKind {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            core::fmt::Debug::fmt(self, f)
+        }
+    }
+
+    impl ErrorKind for TestKind {
+        const OTHER: Self = Self::Other;
+
+        fn uncategorized() -> Self {
+            Self::Uncategorized
+        }
+    }
+
+    impl FromRawOsError for TestKind {
+        fn from_raw_os_error(_raw: RawOsError) -> Self {
+            Self::Other
+        }
+    }
+
+    #[test]
+    fn simple_round_trips() {
+        let err = Error::new_simple(TestKind::Other);
+        assert_eq!(err.kind(), TestKind::Other);
+        assert_eq!(err.raw_os_error(), None);
+    }
+
+    #[test]
+    fn os_round_trips() {
+        let err = Error::<TestKind>::from_raw_os_error(2);
+        assert_eq!(err.raw_os_error(), Some(2));
+    }
+
+    #[test]
+    fn os_round_trips_at_extremes() {
+        // `RawOsError` on this target is the common 32-bit alias, whose full range always
+        // fits in the bitpacked payload - unlike the 64-bit `isize` alias used on
+        // `windows`/`lilium`, which is only exercised indirectly below.
+        for raw in [RawOsError::MIN, RawOsError::MAX] {
+            let err = Error::<TestKind>::from_raw_os_error(raw);
+            assert_eq!(err.raw_os_error(), Some(raw));
+        }
+    }
+
+    #[test]
+    #[cfg(all(feature = "bitpacked", target_pointer_width = "64"))]
+    fn bitpacked_os_repr_stays_one_word() {
+        // Pins down that the bit-packed `Repr` is a single word. This holds for every tag
+        // (boxed payloads store a thin pointer), so it does not by itself show that the `Os`
+        // payload is inline; that is what `Repr::os` does by construction.
+        assert_eq!(core::mem::size_of::<Repr>(), core::mem::size_of::<usize>());
+    }
+
+    #[test]
+    #[cfg(all(feature = "bitpacked", target_pointer_width = "64"))]
+    fn bitpacked_os_packing_loses_bits_for_oversized_isize_magnitude() {
+        // This target's `RawOsError` is `i32`, whose magnitude always fits, so the corruption
+        // `Repr::os`'s `debug_assert!` guards against can't be reproduced through the public
+        // API here. This replicates the exact packing/unpacking arithmetic for an `isize`-sized
+        // code (as used on `windows`/`lilium`) to pin down that large magnitudes really do get
+        // corrupted rather than round-tripping, confirming the assert's condition is meaningful.
+        const TAG_BITS: u32 = 2;
+        let raw: isize = isize::MIN;
+        let packed = ((raw as usize) << TAG_BITS) | 0b01;
+        let unpacked = (packed as isize) >> TAG_BITS;
+        assert_ne!(unpacked, raw, "a full-width isize magnitude should not round-trip");
+    }
+
+    #[test]
+    #[cfg(all(
+        feature = "bitpacked",
+        target_pointer_width = "64",
+        not(feature = "error-track_caller")
+    ))]
+    fn bitpacked_is_two_words() {
+        assert_eq!(
+            core::mem::size_of::<Error<TestKind>>(),
+            2 * core::mem::size_of::<usize>()
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn message_round_trips() {
+        let err = Error::new_with_message(TestKind::Other, "could not find the requested item");
+        let rendered = std::format!("{err}");
+        assert!(
+            rendered.starts_with("Other: could not find the requested item"),
+            "{rendered}"
+        );
+
+        // A `Message` payload can be recovered as a boxed error, but (unlike `Error::new`'s
+        // custom payload) it was never a concrete typed value, so it can't be downcast.
+        let inner = err.into_error().expect("message payload should convert");
+        assert_eq!(std::format!("{inner}"), "could not find the requested item");
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn os_error_description_resolved_under_std() {
+        let err = Error::<TestKind>::from_raw_os_error(2);
+        let rendered = std::format!("{err}");
+        assert!(rendered.contains("No such file or directory"), "{rendered}");
+    }
+
+    #[test]
+    #[cfg(not(feature = "std"))]
+    fn os_error_description_absent_without_std() {
+        let err = Error::<TestKind>::from_raw_os_error(2);
+        let rendered = std::format!("{err}");
+        assert!(rendered.contains("(raw os error 2)"), "{rendered}");
+    }
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    enum OverrideKind {
+        Other,
+        Uncategorized,
+    }
+
+    impl core::fmt::Display for OverrideKind {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            core::fmt::Debug::fmt(self, f)
+        }
+    }
+
+    impl ErrorKind for OverrideKind {
+        const OTHER: Self = Self::Other;
+
+        fn uncategorized() -> Self {
+            Self::Uncategorized
+        }
+
+        fn describe_raw_os_error(raw: RawOsError) -> Option<&'static str> {
+            (raw == 2).then_some("custom override")
+        }
+    }
+
+    impl FromRawOsError for OverrideKind {
+        fn from_raw_os_error(_raw: RawOsError) -> Self {
+            Self::Other
+        }
+    }
+
+    #[test]
+    fn os_error_description_honors_kind_override() {
+        // A kind-supplied `describe_raw_os_error` override must win, whether or not `std` (and
+        // the blanket table it provides) is enabled, and regardless of `bitpacked`.
+        let err = Error::<OverrideKind>::from_raw_os_error(2);
+        let rendered = std::format!("{err}");
+        assert!(rendered.contains("custom override"), "{rendered}");
+    }
+
+    #[derive(Debug)]
+    #[cfg(feature = "alloc")]
+    struct MyError;
+
+    #[cfg(feature = "alloc")]
+    impl core::fmt::Display for MyError {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            f.write_str("my error")
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    impl core::error::Error for MyError {}
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn custom_payload_round_trips() {
+        let mut err = Error::new(TestKind::Other, MyError);
+        assert!(err.get_ref().is_some());
+        assert!(err.get_mut().is_some());
+
+        match err.downcast::<MyError>() {
+            Ok(MyError) => {}
+            Err(_) => panic!("downcast to the concrete payload type should succeed"),
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn downcast_failure_returns_self() {
+        #[derive(Debug)]
+        struct OtherError;
+
+        impl core::fmt::Display for OtherError {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                f.write_str("other error")
+            }
+        }
+
+        impl core::error::Error for OtherError {}
+
+        let err = Error::new(TestKind::Other, MyError);
+        let err = err
+            .downcast::<OtherError>()
+            .expect_err("downcast to an unrelated type should fail");
+        let inner = err.into_inner().unwrap();
+        assert_eq!(std::format!("{inner}"), "my error");
+    }
+
+    #[test]
+    #[cfg(feature = "alloc")]
+    fn source_returns_custom_payload() {
+        use core::error::Error as _;
+
+        let err = Error::new(TestKind::Other, MyError);
+        let source = err.source().expect("custom payload should be a source");
+        assert_eq!(std::format!("{source}"), "my error");
+    }
+
+    #[test]
+    fn source_is_none_without_custom_payload() {
+        use core::error::Error as _;
+
+        let err = Error::new_simple(TestKind::Other);
+        assert!(err.source().is_none());
+    }
+}
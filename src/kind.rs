@@ -11,6 +11,33 @@ pub trait ErrorKind: core::fmt::Debug + core::fmt::Display + Copy {
     /// Returns a value of the [`ErrorKind`] that represents an uncategorized Error.
     /// This should normally not be matachable outside of the crate that defines the [`ErrorKind`]
     fn uncategorized() -> Self;
+
+    /// Returns a human-readable description of a [`RawOsError`] code, if available.
+    ///
+    /// [`core::fmt::Display`] for [`Error`][crate::Error] prefers this over the raw numeric code
+    /// when it returns [`Some`]. Declared here (rather than on [`FromRawOsError`]) so it's
+    /// reachable by kind alone, with no need to also know whether `K` implements
+    /// [`FromRawOsError`] - in particular, this is what lets the `bitpacked` representation
+    /// resolve it at `Display` time without having to carry `K` in the packed word.
+    /// The default returns [`None`]; `no_std` targets (e.g. `lilium`/embedded) can override this with their own static lookup table.
+    #[cfg(not(feature = "std"))]
+    fn describe_raw_os_error(raw: RawOsError) -> Option<&'static str> {
+        let _ = raw;
+        None
+    }
+
+    /// Returns a human-readable description of a [`RawOsError`] code, if available.
+    ///
+    /// [`core::fmt::Display`] for [`Error`][crate::Error] prefers this over the raw numeric code
+    /// when it returns [`Some`]. Declared here (rather than on [`FromRawOsError`]) so it's
+    /// reachable by kind alone, with no need to also know whether `K` implements
+    /// [`FromRawOsError`] - in particular, this is what lets the `bitpacked` representation
+    /// resolve it at `Display` time without having to carry `K` in the packed word.
+    /// Resolved for free through [`std::io::Error`]'s own OS message rendering.
+    #[cfg(feature = "std")]
+    fn describe_raw_os_error(raw: RawOsError) -> Option<&'static str> {
+        std_impls::describe_raw_os_error(raw)
+    }
 }
 
 /// Trait for [`ErrorKind`]s that can be created from a [`RawOsError`]
@@ -43,8 +70,27 @@ pub trait IntoIoKind: ErrorKind {
 }
 
 #[cfg(feature = "std")]
-mod std_impls {
+pub(crate) mod std_impls {
     use super::*;
+
+    /// Resolves a human-readable description of a [`RawOsError`] through [`std::io::Error`]'s own
+    /// OS message rendering, caching one leaked string per distinct error code so repeated calls
+    /// (e.g. on a hot `from_raw_os_error` path) don't leak a fresh string every time.
+    pub(crate) fn describe_raw_os_error(raw: RawOsError) -> Option<&'static str> {
+        use std::collections::HashMap;
+        use std::sync::{Mutex, OnceLock};
+
+        static CACHE: OnceLock<Mutex<HashMap<RawOsError, &'static str>>> = OnceLock::new();
+        let mut cache = CACHE.get_or_init(Default::default).lock().unwrap();
+        if let Some(description) = cache.get(&raw) {
+            return Some(description);
+        }
+        let description =
+            std::string::String::leak(std::io::Error::from_raw_os_error(raw).to_string());
+        cache.insert(raw, description);
+        Some(description)
+    }
+
     impl ErrorKind for std::io::ErrorKind {
         const OTHER: Self = Self::Other;
 
@@ -70,4 +116,21 @@ mod std_impls {
             std::io::Error::from_raw_os_error(raw).kind() // Lol
         }
     }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn describe_raw_os_error_is_cached() {
+            let first = describe_raw_os_error(2).unwrap();
+            let second = describe_raw_os_error(2).unwrap();
+            assert_eq!(first, second);
+            assert_eq!(
+                first.as_ptr(),
+                second.as_ptr(),
+                "expected the cached description to be reused, not leaked again"
+            );
+        }
+    }
 }
@@ -5,8 +5,10 @@
 //! ## Features
 //! * `alloc`: Adds support for interfaces that require an allocator. In particular, this adds error constructors that take arbitrary payloads.
 //! * `std`: Adds support for interfaces that require the standard library (conversions to and from `std::io::Error` and an implementing [`kind::ErrorKind`] for `std::io::ErrorKind`).
-//! * `error-track_caller`: Causes construction of [`Error`] to carry information about the location they were created.
-//!     This is a debugging tool only - it does not add any API interfaces to the crate. Instead, the error simply holds the information internally, which is shown in the [`core::fmt::Debug`] impl.
+//! * `error-track_caller`: Causes construction of [`Error`] to carry a propagation trail: the location it was created, plus every hop recorded with [`Error::track`]/[`Error::track_msg`] (or the [`track!`] macro).
+//!     The trail is shown in the [`core::fmt::Debug`] and [`core::fmt::Display`] impls. With `alloc` available the trail grows to record every hop; without it, only the most recent location (and message) is kept.
+//! * `bitpacked` (64-bit targets only): Collapses the payload of [`Error`] into a single pointer-sized tagged word instead of a multi-word enum, shrinking `size_of::<Error<K>>()` to two words (kind + payload). Requires `alloc`. Has no effect on targets where `usize` is not 64 bits - the ordinary enum-based payload is used there instead.
+//!     The `Os` payload always stays that single allocation-free word: its description is resolved lazily by calling [`kind::ErrorKind::describe_raw_os_error`] (still honoring any override a kind provides) each time the error is displayed rather than at construction; the default table that `std` provides caches its strings per code; without `std` and without an override it renders as the bare numeric code.
 
 mod error;
 /// Module for the [`ErrorKind`][kind::ErrorKind] trait and support traits.
@@ -14,9 +16,141 @@ pub mod kind;
 
 pub use error::Error;
 
-#[cfg(feature = "alloc")]
+/// A convenience alias for a [`core::result::Result`] whose error type is [`Error<K>`][Error].
+///
+/// Follows the same convention as `std::io::Result`.
+pub type Result<T, K> = core::result::Result<T, Error<K>>;
+
+/// Returns early from the enclosing function with an [`Error`] built from `kind` (and, optionally, a message).
+///
+/// ```
+/// # use error_repr::kind::ErrorKind;
+/// # #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// # enum MyKind { NotFound, Uncategorized }
+/// # impl core::fmt::Display for MyKind {
+/// #     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+/// #         core::fmt::Debug::fmt(self, f)
+/// #     }
+/// # }
+/// # impl ErrorKind for MyKind {
+/// #     const OTHER: Self = Self::NotFound;
+/// #     fn uncategorized() -> Self { Self::Uncategorized }
+/// # }
+/// use error_repr::{bail, Result};
+///
+/// fn find(found: bool) -> Result<(), MyKind> {
+///     if !found {
+///         bail!(MyKind::NotFound, "could not find the requested item");
+///     }
+///     Ok(())
+/// }
+///
+/// assert!(find(false).is_err());
+/// assert!(find(true).is_ok());
+/// ```
+#[macro_export]
+macro_rules! bail {
+    ($kind:expr) => {
+        return ::core::result::Result::Err($crate::Error::new_simple($kind))
+    };
+    ($kind:expr, $msg:expr) => {
+        return ::core::result::Result::Err($crate::Error::new_with_message($kind, $msg))
+    };
+}
+
+/// Returns early from the enclosing function with an [`Error`] built from `kind` and `msg` unless `cond` holds.
+///
+/// ```
+/// # use error_repr::kind::ErrorKind;
+/// # #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// # enum MyKind { NotFound, Uncategorized }
+/// # impl core::fmt::Display for MyKind {
+/// #     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+/// #         core::fmt::Debug::fmt(self, f)
+/// #     }
+/// # }
+/// # impl ErrorKind for MyKind {
+/// #     const OTHER: Self = Self::NotFound;
+/// #     fn uncategorized() -> Self { Self::Uncategorized }
+/// # }
+/// use error_repr::{ensure, Result};
+///
+/// fn check(exists: bool) -> Result<(), MyKind> {
+///     ensure!(exists, MyKind::NotFound, "could not find the requested item");
+///     Ok(())
+/// }
+///
+/// assert!(check(false).is_err());
+/// assert!(check(true).is_ok());
+/// ```
+#[macro_export]
+macro_rules! ensure {
+    ($cond:expr, $kind:expr, $msg:expr) => {
+        if !$cond {
+            $crate::bail!($kind, $msg)
+        }
+    };
+}
+
+#[cfg(any(
+    feature = "alloc",
+    all(feature = "bitpacked", target_pointer_width = "64")
+))]
 extern crate alloc;
 
+/// Annotates the `Err` arm of a `Result<_, Error<K>>` expression with the caller's location, via
+/// [`Error::track`] (or [`Error::track_msg`] when given a message).
+///
+/// ```
+/// # use error_repr::kind::ErrorKind;
+/// # #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// # enum MyKind { Other, Uncategorized }
+/// # impl core::fmt::Display for MyKind {
+/// #     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+/// #         core::fmt::Debug::fmt(self, f)
+/// #     }
+/// # }
+/// # impl ErrorKind for MyKind {
+/// #     const OTHER: Self = Self::Other;
+/// #     fn uncategorized() -> Self { Self::Uncategorized }
+/// # }
+/// use error_repr::{track, Error, Result};
+///
+/// fn fallible_call() -> Result<i32, MyKind> {
+///     Err(Error::new_simple(MyKind::Other))
+/// }
+///
+/// fn wrapper() -> Result<i32, MyKind> {
+///     let value = track!(fallible_call(), "while loading config")?;
+///     Ok(value)
+/// }
+///
+/// let err = wrapper().unwrap_err();
+/// // The most recent hop is the `track!` call site, with the message it was given.
+/// let (_location, msg) = err.locations().next_back().unwrap();
+/// assert_eq!(msg, Some("while loading config"));
+/// ```
+#[cfg(feature = "error-track_caller")]
+#[macro_export]
+macro_rules! track {
+    ($result:expr) => {
+        match $result {
+            ::core::result::Result::Ok(ok) => ::core::result::Result::Ok(ok),
+            ::core::result::Result::Err(err) => {
+                ::core::result::Result::Err($crate::Error::track(err))
+            }
+        }
+    };
+    ($result:expr, $msg:expr) => {
+        match $result {
+            ::core::result::Result::Ok(ok) => ::core::result::Result::Ok(ok),
+            ::core::result::Result::Err(err) => {
+                ::core::result::Result::Err($crate::Error::track_msg(err, $msg))
+            }
+        }
+    };
+}
+
 cfg_match::cfg_match! {
     any(target_os = "windows", target_os = "lilium") => {
         /// The raw type of an OS Error.